@@ -0,0 +1,59 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::location::Location;
+
+/// Per-column min/max/null-count summary, used by the range filter to decide whether a
+/// block can possibly satisfy a predicate without reading any of its data.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ColumnStatistics {
+    pub min: common_datavalues::DataValue,
+    pub max: common_datavalues::DataValue,
+    pub null_count: u64,
+    pub in_memory_size: u64,
+}
+
+/// Metadata for a single data block: row count, per-column statistics, and the locations
+/// of the auxiliary indexes/bitmaps that let `BlockPruner` decide whether the block needs
+/// to be read at all.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BlockMeta {
+    pub row_count: u64,
+    pub col_stats: HashMap<u32, ColumnStatistics>,
+    pub bloom_filter_index_location: Option<Location>,
+    pub bloom_filter_index_size: u64,
+
+    /// Location of this block's deletion bitmap (a packed validity vector, one bit per
+    /// row, unset where the row has been soft-deleted by a merge-on-read `DELETE`/`UPDATE`).
+    /// `None` means the block has no recorded deletes: every row is still live.
+    /// `#[serde(default)]` lets snapshots written before deletion vectors existed continue
+    /// to deserialize, with every block treated as fully live.
+    #[serde(default)]
+    pub deletion_bitmap_location: Option<Location>,
+    /// Byte length of the deletion bitmap at `deletion_bitmap_location`. Unused (and left
+    /// at its default of `0`) when there is no deletion bitmap.
+    #[serde(default)]
+    pub deletion_bitmap_size: u64,
+    /// Rows among `row_count` that `deletion_bitmap_location` marks as soft-deleted, known at
+    /// the time the bitmap was written — cheap enough to carry alongside it so `BlockPruner`
+    /// can tell how many of this block's rows are actually live without fetching and scanning
+    /// the bitmap bytes themselves. `0` (the default) when there is no deletion bitmap.
+    #[serde(default)]
+    pub deleted_row_count: u64,
+}
@@ -0,0 +1,41 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::block::BlockMeta;
+use super::block::ColumnStatistics;
+
+/// Whole-segment summary, cheap enough to read once per `prune` call even when most of
+/// its blocks end up being skipped.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Statistics {
+    pub row_count: u64,
+    /// Per-column min/max/null-count, aggregated across every block in the segment. Lets
+    /// `RangeFilterPruner` rule out the whole segment in one check, the same way
+    /// `BlockMeta::col_stats` rules out a single block, instead of range-filtering every
+    /// block individually when the segment-level summary already can't satisfy the
+    /// predicate.
+    pub col_stats: HashMap<u32, ColumnStatistics>,
+}
+
+/// A segment: a summary plus the full list of blocks it contains.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SegmentInfo {
+    pub summary: Statistics,
+    pub blocks: Vec<BlockMeta>,
+}
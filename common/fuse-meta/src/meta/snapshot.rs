@@ -0,0 +1,42 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::location::Location;
+
+/// A segment's location, paired with the row-count statistics `BlockPruner` needs to decide
+/// whether the segment could possibly fall inside a requested OFFSET+LIMIT slice. Carrying
+/// these alongside the location, directly in the snapshot, lets that Before/Overlapping/After
+/// decision be made without first reading the segment's full info (summary + block list)
+/// through an object-store round trip — the snapshot is already resident by the time pruning
+/// starts, so this is free.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SegmentStats {
+    pub location: Location,
+    pub row_count: u64,
+    /// Rows among `row_count` that this segment's blocks' deletion bitmaps mark as
+    /// soft-deleted. Mirrors the sum of the segment's own blocks' `BlockMeta::deleted_row_count`
+    /// at the time the segment was written.
+    #[serde(default)]
+    pub deleted_row_count: u64,
+}
+
+/// A table snapshot: the ordered list of segments that made up the table at commit time,
+/// each paired with enough row-count info to prune by OFFSET/LIMIT without reading it.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshot {
+    pub segments: Vec<SegmentStats>,
+}
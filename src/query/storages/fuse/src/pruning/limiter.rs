@@ -0,0 +1,195 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::sync::atomic::AtomicI64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+pub type LimiterPruner = Arc<dyn Limiter + Send + Sync>;
+
+/// Where a block's row range sits relative to the requested `[offset, offset + len)` slice.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlicePosition {
+    /// the block ends at or before `offset`: it contributes nothing to the slice and can
+    /// be skipped without reading its index.
+    Before,
+    /// the block overlaps the slice. `start`/`len` are the row range, local to the block,
+    /// that actually falls inside `[offset, offset + len)`.
+    Overlapping { start: u64, len: u64 },
+    /// the block starts at or after `offset + len`: everything from here on is also After,
+    /// so the caller can stop scanning the remaining blocks/segments.
+    After,
+}
+
+/// Classifies a block spanning `[rows_seen, rows_seen + row_count)` against the requested
+/// `[offset, offset + len)` slice. `rows_seen` is the count of rows, in snapshot order,
+/// that precede this block.
+pub fn classify_slice(rows_seen: u64, row_count: u64, offset: u64, len: u64) -> SlicePosition {
+    let block_end = rows_seen + row_count;
+    let slice_end = offset.saturating_add(len);
+
+    if block_end <= offset {
+        SlicePosition::Before
+    } else if rows_seen >= slice_end {
+        SlicePosition::After
+    } else {
+        let start = offset.saturating_sub(rows_seen);
+        let end = slice_end.min(block_end) - rows_seen;
+        SlicePosition::Overlapping {
+            start,
+            len: end - start,
+        }
+    }
+}
+
+/// Resolves a possibly negative ("from the end") offset against the table's total row
+/// count, as recorded in the snapshot summary.
+pub fn resolve_offset(offset: i64, total_row_count: u64) -> u64 {
+    if offset >= 0 {
+        offset as u64
+    } else {
+        total_row_count.saturating_sub(offset.unsigned_abs())
+    }
+}
+
+pub trait Limiter {
+    // Once this returns true, enough rows have already been accounted for and the
+    // remaining blocks/segments can stop being scanned.
+    fn exceeded(&self) -> bool;
+
+    // Accounts `row_count` more rows towards the requested slice. Returns false once the
+    // limiter is already exhausted, so the caller can skip the (expensive) bloom lookup.
+    fn within_limit(&self, row_count: u64) -> bool;
+}
+
+struct DummyLimiter;
+
+impl Limiter for DummyLimiter {
+    fn exceeded(&self) -> bool {
+        false
+    }
+
+    fn within_limit(&self, _row_count: u64) -> bool {
+        true
+    }
+}
+
+// `Before`/`After` blocks are already eliminated by `classify_slice` before a block ever
+// reaches the limiter, so all that is left to track here is how many of the `len` rows of
+// the slice are still outstanding.
+struct SliceLimiter {
+    remain: AtomicI64,
+}
+
+impl Limiter for SliceLimiter {
+    fn exceeded(&self) -> bool {
+        self.remain.load(Ordering::Acquire) <= 0
+    }
+
+    fn within_limit(&self, row_count: u64) -> bool {
+        if self.exceeded() {
+            return false;
+        }
+        self.remain.fetch_sub(row_count as i64, Ordering::AcqRel);
+        true
+    }
+}
+
+// prepare the limiter. in case that limit is none, an unlimited limiter will be returned
+pub fn new_limiter(limit: Option<usize>) -> LimiterPruner {
+    match limit {
+        Some(v) => Arc::new(SliceLimiter {
+            remain: AtomicI64::new(v as i64),
+        }),
+        None => Arc::new(DummyLimiter),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_slice_before_and_after() {
+        // block ends exactly at offset: contributes nothing, classified Before.
+        assert_eq!(classify_slice(0, 10, 10, 5), SlicePosition::Before);
+        // block starts exactly at offset + len: classified After.
+        assert_eq!(classify_slice(15, 10, 10, 5), SlicePosition::After);
+    }
+
+    #[test]
+    fn classify_slice_overlap_boundaries() {
+        // slice [10, 15) against a block spanning rows [5, 20): only [10, 15) local to
+        // the block, i.e. start = 5, len = 5.
+        assert_eq!(classify_slice(5, 15, 10, 5), SlicePosition::Overlapping {
+            start: 5,
+            len: 5
+        });
+        // block fully contained inside the slice: the whole block overlaps.
+        assert_eq!(classify_slice(10, 5, 0, 100), SlicePosition::Overlapping {
+            start: 0,
+            len: 5
+        });
+    }
+
+    #[test]
+    fn classify_slice_zero_length_slice() {
+        // len == 0 means slice_end == offset: a block entirely before that point is still
+        // Before, and a block starting at or after it is still After.
+        assert_eq!(classify_slice(0, 5, 5, 0), SlicePosition::Before);
+        assert_eq!(classify_slice(5, 10, 5, 0), SlicePosition::After);
+        // a block straddling `offset` overlaps, but with a zero-length slice inside it.
+        assert_eq!(classify_slice(0, 10, 5, 0), SlicePosition::Overlapping {
+            start: 5,
+            len: 0
+        });
+    }
+
+    #[test]
+    fn resolve_offset_non_negative_passes_through() {
+        assert_eq!(resolve_offset(0, 100), 0);
+        assert_eq!(resolve_offset(42, 100), 42);
+    }
+
+    #[test]
+    fn resolve_offset_negative_counts_from_the_end() {
+        assert_eq!(resolve_offset(-1, 100), 99);
+        assert_eq!(resolve_offset(-10, 100), 90);
+    }
+
+    #[test]
+    fn resolve_offset_negative_past_the_start_saturates_to_zero() {
+        assert_eq!(resolve_offset(-1000, 100), 0);
+    }
+
+    #[test]
+    fn slice_limiter_exhausts_after_charging_limit_rows() {
+        let limiter = new_limiter(Some(10));
+        assert!(!limiter.exceeded());
+        assert!(limiter.within_limit(6));
+        assert!(!limiter.exceeded());
+        assert!(limiter.within_limit(4));
+        assert!(limiter.exceeded());
+        // once exceeded, within_limit refuses to charge any more rows.
+        assert!(!limiter.within_limit(1));
+    }
+
+    #[test]
+    fn dummy_limiter_never_exceeds() {
+        let limiter = new_limiter(None);
+        assert!(!limiter.exceeded());
+        assert!(limiter.within_limit(u64::MAX));
+        assert!(!limiter.exceeded());
+    }
+}
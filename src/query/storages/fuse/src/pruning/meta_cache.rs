@@ -0,0 +1,289 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+//! A small, process-wide, capacity-bounded cache for the pieces of table metadata that
+//! `BlockPruner` re-reads on every `prune` call: segment info, per-block deletion bitmaps,
+//! and per-block bloom-filter indexes. Repeated pruning of the same table (point lookups,
+//! dashboards refreshing the same query) would otherwise pay full object-store IO every
+//! single time.
+
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use common_fuse_meta::meta::Location;
+use once_cell::sync::OnceCell;
+
+// slab index into `Inner::nodes`
+type NodeIndex = usize;
+
+struct Node<V> {
+    key: Location,
+    value: Arc<V>,
+    prev: Option<NodeIndex>,
+    next: Option<NodeIndex>,
+}
+
+// A classic LRU: a hash map from `Location` to a slab slot, plus an intrusive
+// doubly-linked recency list threaded through the same slab. `head` is the most
+// recently used entry, `tail` the next one to be evicted.
+struct Inner<V> {
+    index: HashMap<Location, NodeIndex>,
+    nodes: Vec<Node<V>>,
+    free: Vec<NodeIndex>,
+    head: Option<NodeIndex>,
+    tail: Option<NodeIndex>,
+}
+
+impl<V> Inner<V> {
+    fn detach(&mut self, idx: NodeIndex) {
+        let (prev, next) = (self.nodes[idx].prev, self.nodes[idx].next);
+        match prev {
+            Some(prev) => self.nodes[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.nodes[next].prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn push_front(&mut self, idx: NodeIndex) {
+        self.nodes[idx].prev = None;
+        self.nodes[idx].next = self.head;
+        if let Some(head) = self.head {
+            self.nodes[head].prev = Some(idx);
+        }
+        self.head = Some(idx);
+        if self.tail.is_none() {
+            self.tail = Some(idx);
+        }
+    }
+
+    fn touch(&mut self, idx: NodeIndex) {
+        if self.head == Some(idx) {
+            return;
+        }
+        self.detach(idx);
+        self.push_front(idx);
+    }
+
+    fn evict_back(&mut self) {
+        if let Some(tail) = self.tail {
+            self.detach(tail);
+            self.index.remove(&self.nodes[tail].key);
+            // keep the slab slot around for reuse instead of shifting the Vec
+            self.free.push(tail);
+        }
+    }
+}
+
+/// A thread-safe, capacity-bounded LRU cache keyed by `Location` (path + version), so
+/// that a stale read of a since-rewritten segment/index never collides with the new one.
+/// Decoded values are stored behind `Arc` so that many concurrently-pruned segments can
+/// share a single copy instead of each paying their own decode cost.
+pub struct LruCache<V> {
+    inner: Mutex<Inner<V>>,
+    capacity: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl<V> LruCache<V> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                index: HashMap::with_capacity(capacity),
+                nodes: Vec::with_capacity(capacity),
+                free: Vec::new(),
+                head: None,
+                tail: None,
+            }),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn get(&self, key: &Location) -> Option<Arc<V>> {
+        let mut inner = self.inner.lock().unwrap();
+        let idx = *inner.index.get(key)?;
+        inner.touch(idx);
+        Some(inner.nodes[idx].value.clone())
+    }
+
+    fn insert(&self, key: Location, value: Arc<V>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(&idx) = inner.index.get(&key) {
+            inner.nodes[idx].value = value;
+            inner.touch(idx);
+            return;
+        }
+        if inner.index.len() >= self.capacity {
+            inner.evict_back();
+        }
+        let idx = match inner.free.pop() {
+            Some(idx) => {
+                inner.nodes[idx] = Node {
+                    key: key.clone(),
+                    value,
+                    prev: None,
+                    next: None,
+                };
+                idx
+            }
+            None => {
+                inner.nodes.push(Node {
+                    key: key.clone(),
+                    value,
+                    prev: None,
+                    next: None,
+                });
+                inner.nodes.len() - 1
+            }
+        };
+        inner.index.insert(key, idx);
+        inner.push_front(idx);
+    }
+
+    /// Reads through the cache: on a hit, the cached `Arc` is returned without running
+    /// `load`; on a miss, `load` is awaited, the result is inserted at the front of the
+    /// recency list (evicting from the back if the cache is at capacity), and returned.
+    /// `load` is expected to already produce an `Arc<V>`, matching the decoded, shareable
+    /// values that `MetaReaders` hands back.
+    pub async fn get_or_load<F, Fut, E>(&self, key: &Location, load: F) -> Result<Arc<V>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: std::future::Future<Output = Result<Arc<V>, E>>,
+    {
+        if let Some(hit) = self.get(key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(hit);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let value = load().await?;
+        self.insert(key.clone(), value.clone());
+        Ok(value)
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
+/// The metadata caches `BlockPruner` reads through: segment info (whole-segment summaries
+/// + block list), per-block deletion bitmaps, and per-block bloom-filter index bytes. All
+/// three are fetched read-only and keyed by `Location` (path + version), so caching them is
+/// purely a question of IO reuse: `pruner::Pruner::should_keep` takes the index bytes
+/// already fetched through `bloom_indexes`, rather than fetching them itself.
+pub struct MetaCache {
+    pub segments: LruCache<common_fuse_meta::meta::SegmentInfo>,
+    pub deletion_bitmaps: LruCache<Vec<u8>>,
+    pub bloom_indexes: LruCache<Vec<u8>>,
+}
+
+impl MetaCache {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            segments: LruCache::with_capacity(capacity),
+            deletion_bitmaps: LruCache::with_capacity(capacity),
+            bloom_indexes: LruCache::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the process-wide cache, creating it with `capacity` on first use. Later
+    /// calls with a different `capacity` are ignored: the cache is sized once, by
+    /// whichever query first touches it, for the lifetime of the process.
+    pub fn global(capacity: usize) -> &'static MetaCache {
+        static INSTANCE: OnceCell<MetaCache> = OnceCell::new();
+        INSTANCE.get_or_init(|| MetaCache::with_capacity(capacity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn loc(i: u64) -> Location {
+        (format!("loc-{i}"), 0)
+    }
+
+    #[test]
+    fn insert_and_get_roundtrip() {
+        let cache: LruCache<u32> = LruCache::with_capacity(2);
+        cache.insert(loc(1), Arc::new(100));
+        assert_eq!(*cache.get(&loc(1)).unwrap(), 100);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_when_over_capacity() {
+        let cache: LruCache<u32> = LruCache::with_capacity(2);
+        cache.insert(loc(1), Arc::new(1));
+        cache.insert(loc(2), Arc::new(2));
+        cache.insert(loc(3), Arc::new(3)); // evicts loc(1), the LRU entry
+        assert!(cache.get(&loc(1)).is_none());
+        assert!(cache.get(&loc(2)).is_some());
+        assert!(cache.get(&loc(3)).is_some());
+    }
+
+    #[test]
+    fn get_refreshes_recency_so_it_survives_eviction() {
+        let cache: LruCache<u32> = LruCache::with_capacity(2);
+        cache.insert(loc(1), Arc::new(1));
+        cache.insert(loc(2), Arc::new(2));
+        // touch loc(1) so loc(2) becomes the least recently used
+        assert!(cache.get(&loc(1)).is_some());
+        cache.insert(loc(3), Arc::new(3)); // evicts loc(2), not loc(1)
+        assert!(cache.get(&loc(1)).is_some());
+        assert!(cache.get(&loc(2)).is_none());
+        assert!(cache.get(&loc(3)).is_some());
+    }
+
+    #[test]
+    fn reinserting_an_existing_key_updates_value_without_growing() {
+        let cache: LruCache<u32> = LruCache::with_capacity(2);
+        cache.insert(loc(1), Arc::new(1));
+        cache.insert(loc(1), Arc::new(42));
+        assert_eq!(*cache.get(&loc(1)).unwrap(), 42);
+    }
+
+    #[test]
+    fn zero_capacity_cache_never_retains_anything() {
+        let cache: LruCache<u32> = LruCache::with_capacity(0);
+        cache.insert(loc(1), Arc::new(1));
+        assert!(cache.get(&loc(1)).is_none());
+    }
+
+    #[test]
+    fn reused_slab_slots_dont_leak_stale_entries() {
+        // after an eviction frees a slab slot, a later insert must reuse it without
+        // resurrecting the evicted key.
+        let cache: LruCache<u32> = LruCache::with_capacity(1);
+        cache.insert(loc(1), Arc::new(1));
+        cache.insert(loc(2), Arc::new(2)); // evicts loc(1), frees its slot
+        cache.insert(loc(3), Arc::new(3)); // evicts loc(2), reuses the freed slot
+        assert!(cache.get(&loc(1)).is_none());
+        assert!(cache.get(&loc(2)).is_none());
+        assert!(cache.get(&loc(3)).is_some());
+    }
+}
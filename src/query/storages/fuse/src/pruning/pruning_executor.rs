@@ -14,10 +14,7 @@
 
 use std::sync::Arc;
 
-use common_base::base::tokio::sync::OwnedSemaphorePermit;
-use common_base::base::tokio::sync::Semaphore;
-use common_base::base::Runtime;
-use common_base::base::TrySpawn;
+use common_arrow::arrow::bitmap::util::count_zeros;
 use common_catalog::table_context::TableContext;
 use common_datavalues::DataSchemaRef;
 use common_exception::ErrorCode;
@@ -27,13 +24,17 @@ use common_fuse_meta::meta::Location;
 use common_fuse_meta::meta::TableSnapshot;
 use common_legacy_planners::Extras;
 use futures::future;
-use tracing::warn;
+use futures::stream::FuturesUnordered;
+use futures::StreamExt;
+use opendal::Operator;
+use rayon::prelude::*;
 use tracing::Instrument;
 
 use super::pruner;
 use crate::io::MetaReaders;
 use crate::pruning::limiter;
 use crate::pruning::limiter::LimiterPruner;
+use crate::pruning::meta_cache;
 use crate::pruning::pruner::Pruner;
 use crate::pruning::range_pruner;
 use crate::pruning::range_pruner::RangeFilterPruner;
@@ -45,6 +46,33 @@ pub struct BlockPruner {
 pub type SegmentIndex = usize;
 pub type BlockIndex = usize;
 
+// Blocks are handed to the range-filter worker pool in chunks rather than one at a time,
+// so a worker amortizes its steal overhead over a batch instead of per block.
+const RANGE_FILTER_CHUNK_SIZE: usize = 64;
+
+// Fallback meta cache size for a misconfigured (zero) `table_meta_cache_capacity` setting.
+// Sized generously enough that a handful of recently-pruned tables' segment info, deletion
+// bitmaps and bloom indexes stay resident by default.
+const DEFAULT_TABLE_META_CACHE_CAPACITY: usize = 4096;
+
+// A block that survived segment/offset elimination and is still waiting on the
+// (possibly expensive) range filter evaluation over its `col_stats`.
+struct Candidate {
+    segment_idx: SegmentIndex,
+    block_meta: BlockMeta,
+    // The row range, local to this block, that the OFFSET+LIMIT walk still needs evaluated.
+    // For a block with no soft-deletes (`deleted_row_count == 0`), this is the exact
+    // `[slice_start, slice_start + slice_len)` produced by `limiter::classify_slice`: live and
+    // physical row positions coincide, so it can be trusted as-is. For a block that does have
+    // deletes, `classify_slice` was run against *live* row counts (see `live_row_count`) to get
+    // the Before/Overlapping/After call right, but the live-ordinal range it returns doesn't
+    // necessarily line up with physical bitmap bit positions once a block's deletes aren't a
+    // single contiguous run — so in that case this covers the whole physical block instead,
+    // and `evaluate_candidate`/`live_row_count_in_range` reconcile against the real bitmap.
+    slice_start: u64,
+    slice_len: u64,
+}
+
 impl BlockPruner {
     pub fn new(table_snapshot: Arc<TableSnapshot>) -> Self {
         Self { table_snapshot }
@@ -54,6 +82,11 @@ impl BlockPruner {
     //
     // Please note that it will take a significant period of time to prune a large table, and
     // thread that calls this method will be blocked.
+    //
+    // `prune` offloads range-filter evaluation onto `spawn_blocking`, which panics unless
+    // it is run from inside a Tokio runtime. `futures::executor::block_on` does not provide
+    // one, so a small dedicated runtime is spun up here for the sync caller, the same
+    // contract the baseline's `Runtime::with_worker_threads` used to provide.
     #[tracing::instrument(level = "debug", skip(self, schema, ctx), fields(ctx.id = ctx.get_id().as_str()))]
     pub fn sync_prune(
         &self,
@@ -61,33 +94,74 @@ impl BlockPruner {
         schema: DataSchemaRef,
         push_down: &Option<Extras>,
     ) -> Result<Vec<(SegmentIndex, BlockMeta)>> {
-        futures::executor::block_on(self.prune(ctx, schema, push_down))
+        let runtime =
+            common_base::base::Runtime::with_worker_threads(1, Some("sync-block-pruner".to_string()))?;
+        runtime.block_on(self.prune(ctx, schema, push_down))
     }
 
     // prune blocks by utilizing min_max index and filter, according to the pushdowns
-    #[tracing::instrument(level = "debug", skip(self, schema, ctx), fields(ctx.id = ctx.get_id().as_str()))]
+    #[tracing::instrument(
+        level = "debug",
+        skip(self, schema, ctx),
+        fields(
+            ctx.id = ctx.get_id().as_str(),
+            segment_cache.hits = tracing::field::Empty,
+            segment_cache.misses = tracing::field::Empty,
+            bloom_index_cache.hits = tracing::field::Empty,
+            bloom_index_cache.misses = tracing::field::Empty,
+            deletion_bitmap_cache.hits = tracing::field::Empty,
+            deletion_bitmap_cache.misses = tracing::field::Empty,
+        )
+    )]
     pub async fn prune(
         &self,
         ctx: &Arc<dyn TableContext>,
         schema: DataSchemaRef,
         push_down: &Option<Extras>,
     ) -> Result<Vec<(SegmentIndex, BlockMeta)>> {
-        let segment_locs = self.table_snapshot.segments.clone();
+        let segments_meta = self.table_snapshot.segments.clone();
 
-        if segment_locs.is_empty() {
+        if segments_meta.is_empty() {
             return Ok(vec![]);
         };
 
-        // if there are ordering clause, ignore limit, even it has been pushed down
+        // if there are ordering clause, ignore limit/offset, even if they have been pushed down
         let limit = push_down
             .as_ref()
             .filter(|p| p.order_by.is_empty())
             .and_then(|p| p.limit);
+        let raw_offset = push_down
+            .as_ref()
+            .filter(|p| p.order_by.is_empty())
+            .and_then(|p| p.offset)
+            .unwrap_or(0);
 
         let filter_expressions = push_down.as_ref().map(|extra| extra.filters.as_slice());
 
         // 1. prepare pruners
 
+        // Segment row counts are carried directly on the snapshot (`SegmentStats`), so the
+        // cumulative row offset used below to classify each segment as Before/Overlapping/After
+        // the requested slice is pure arithmetic over data that is already resident — no
+        // object-store round trip needed just to make that decision. The cumulative offset is
+        // built from *live* row counts, not raw ones: OFFSET/LIMIT count over the rows a query
+        // can actually see, and a segment earlier in snapshot order whose blocks are wholly or
+        // partly soft-deleted must not be allowed to "use up" slice budget it never occupies —
+        // doing so would wrongly classify a later, fully-live segment as `After` and skip it
+        // (and its live rows) entirely.
+        let mut segment_row_offsets = Vec::with_capacity(segments_meta.len());
+        let mut rows_seen = 0u64;
+        for segment in &segments_meta {
+            segment_row_offsets.push(rows_seen);
+            rows_seen += Self::live_row_count(segment.row_count, segment.deleted_row_count);
+        }
+        let total_row_count = rows_seen;
+
+        // a negative offset means "from the end", which can only be resolved once the
+        // table's total row count (above) is known.
+        let offset = limiter::resolve_offset(raw_offset, total_row_count);
+        let slice_len = limit.map(|v| v as u64).unwrap_or(u64::MAX);
+
         // prepare the limiter. in case that limit is none, an unlimited limiter will be returned
         let limiter = limiter::new_limiter(limit);
 
@@ -96,98 +170,244 @@ impl BlockPruner {
         let range_filter_pruner =
             range_pruner::new_range_filter_pruner(ctx, filter_expressions, &schema)?;
 
-        // prepare the filter, if filter_expression is none, an dummy pruner will be returned
+        // prepare the filter, if filter_expression is none, an dummy pruner will be returned.
+        // `filter_pruner` only evaluates already-fetched index bytes; it does not fetch
+        // anything itself, so it needs no `dal` handle.
         let dal = ctx.get_storage_operator()?;
-        let filter_pruner = pruner::new_filter_pruner(ctx, filter_expressions, &schema, dal)?;
+        let filter_pruner = pruner::new_filter_pruner(ctx, filter_expressions, &schema)?;
 
-        // 2. kick off
-        //
-        // As suggested by Winter, to make the pruning process more parallel (not just concurrent),
-        // we create a dedicated runtime for pruning tasks.
-        //
-        // NOTE:
-        // A. To simplify things, an optimistic way of error handling is taken: errors are handled
-        // at the "collect" phase. e.g. if anything goes wrong, we do not break the whole
-        // pruning task immediately, but only at the time that all tasks are done
-        //
-        // B. since limiter is working concurrently, we arrange some checks among the pruning,
-        //    to avoid heavy io operation vainly,
-        let max_threads = ctx.get_settings().get_max_threads()? as usize;
-        let pruning_runtime =
-            Runtime::with_worker_threads(max_threads, Some("pruning-worker".to_owned()))?;
+        let max_concurrent_prune = ctx.get_settings().get_max_concurrent_prune()? as usize;
+        // Prevent us from miss-configured max_concurrent_prune setting, e.g. 0
+        let max_concurrent_prune = std::cmp::max(max_concurrent_prune, 10);
+
+        let table_meta_cache_capacity = ctx.get_settings().get_table_meta_cache_capacity()? as usize;
+        // Prevent us from miss-configured table_meta_cache_capacity setting, e.g. 0
+        let table_meta_cache_capacity = if table_meta_cache_capacity == 0 {
+            DEFAULT_TABLE_META_CACHE_CAPACITY
+        } else {
+            table_meta_cache_capacity
+        };
 
-        let max_concurrent_prune_setting = ctx.get_settings().get_max_concurrent_prune()? as usize;
+        // Classify every segment from its `SegmentStats` alone, before any info is read.
+        // Segments entirely outside the requested slice are dropped here for free: their
+        // info (summary + block list) is never fetched at all, not even through the cache.
+        // Classified by live row count, same reasoning as `segment_row_offsets` above.
+        let mut overlapping_segment_indices = Vec::new();
+        for (segment_idx, segment) in segments_meta.iter().enumerate() {
+            match limiter::classify_slice(
+                segment_row_offsets[segment_idx],
+                Self::live_row_count(segment.row_count, segment.deleted_row_count),
+                offset,
+                slice_len,
+            ) {
+                limiter::SlicePosition::Before => continue,
+                limiter::SlicePosition::After => break,
+                limiter::SlicePosition::Overlapping { .. } => {
+                    overlapping_segment_indices.push(segment_idx)
+                }
+            }
+        }
 
-        // Prevent us from miss-configured max_concurrent_prune setting, e.g. 0
+        // Read through the process-wide meta cache so that repeated pruning of the same
+        // table (point lookups, dashboards re-running the same query) does not pay full IO
+        // every time. Sized from the `table_meta_cache_capacity` session setting rather than
+        // a fixed constant, so it can be tuned per deployment.
+        let meta_cache = meta_cache::MetaCache::global(table_meta_cache_capacity);
+        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+        let segment_infos = future::try_join_all(overlapping_segment_indices.iter().map(
+            |&segment_idx| {
+                let segment_reader = &segment_reader;
+                let location = segments_meta[segment_idx].location.clone();
+                async move {
+                    let (seg_loc, ver) = location.clone();
+                    let segment_info = meta_cache
+                        .segments
+                        .get_or_load(&location, || async move {
+                            segment_reader.read(seg_loc, None, ver).await
+                        })
+                        .await?;
+                    Ok((segment_idx, segment_info))
+                }
+            },
+        ))
+        .await?;
+
+        tracing::Span::current().record("segment_cache.hits", meta_cache.segments.hits());
+        tracing::Span::current().record("segment_cache.misses", meta_cache.segments.misses());
+
+        // 2. Collect candidate blocks.
         //
-        // note that inside the segment pruning, the same semaphore is used to
-        // control the concurrency of block pruning, to prevent us from waiting for
-        // a permit while hold the last permit, at least 2 permits should be
-        // given to this semaphore:
-        let max_concurrent_prune = std::cmp::max(max_concurrent_prune_setting, 10);
-        if max_concurrent_prune > max_concurrent_prune_setting {
-            warn!(
-                "max_concurrent_prune is too low {}, increased to {}",
-                max_concurrent_prune_setting, max_concurrent_prune
-            )
+        // Only segments that survived the cheap classification above reach this point, so
+        // every one of them is genuinely Overlapping: walking their blocks in order to decide
+        // Before/Overlapping/After is pure row-count arithmetic, so it stays on the calling
+        // thread. A segment whose own summary `col_stats` can't satisfy the range filter is
+        // skipped wholesale: there is no point enumerating and individually range-filtering
+        // every one of its blocks when the cheaper, single summary-level check already rules
+        // the whole segment out.
+        let mut candidates = Vec::new();
+        for (segment_idx, segment_info) in segment_infos {
+            if !range_filter_pruner.should_keep(
+                &segment_info.summary.col_stats,
+                segment_info.summary.row_count,
+            ) {
+                continue;
+            }
+
+            let mut rows_seen = segment_row_offsets[segment_idx];
+            for block_meta in &segment_info.blocks {
+                let live_block_row_count =
+                    Self::live_row_count(block_meta.row_count, block_meta.deleted_row_count);
+                let slice_position =
+                    limiter::classify_slice(rows_seen, live_block_row_count, offset, slice_len);
+                rows_seen += live_block_row_count;
+
+                match slice_position {
+                    limiter::SlicePosition::Before => continue,
+                    limiter::SlicePosition::After => break,
+                    limiter::SlicePosition::Overlapping { start, len } => {
+                        // Live and physical row positions coincide when the block has no
+                        // deletes; `start`/`len` can be trusted directly. Otherwise they are
+                        // live-ordinal positions that don't necessarily map to the same
+                        // physical bit offsets, so the whole block is handed along instead,
+                        // for `evaluate_candidate` to reconcile against the real bitmap.
+                        let (slice_start, slice_len) = if block_meta.deleted_row_count == 0 {
+                            (start, len)
+                        } else {
+                            (0, block_meta.row_count)
+                        };
+                        candidates.push(Candidate {
+                            segment_idx,
+                            block_meta: block_meta.clone(),
+                            slice_start,
+                            slice_len,
+                        })
+                    }
+                }
+            }
         }
 
-        let semaphore = Arc::new(Semaphore::new(max_concurrent_prune));
-        let rt_ref = Arc::new(pruning_runtime);
-        let mut join_handlers = Vec::with_capacity(segment_locs.len());
-        for (segment_idx, segment_location) in segment_locs.into_iter().enumerate() {
-            let ctx = ctx.clone();
+        // 3. Evaluate the range filter (min/max pruning) over every candidate's `col_stats`.
+        //
+        // This is CPU-bound and, unlike the bloom index lookup, needs no IO at all, so it is
+        // handed to rayon's work-stealing thread pool instead of being driven by the async
+        // runtime: idle workers steal batches of blocks from segments that turned out to have
+        // more candidates than others, instead of one segment's range filtering blocking a
+        // dedicated async task while other segments finish early. Blocks are folded into a
+        // local `Vec` per chunk before the final reduce, so a worker pays the steal/fold
+        // overhead once per batch rather than once per block.
+        let filtered = {
             let range_filter_pruner = range_filter_pruner.clone();
-            let filter_pruner = filter_pruner.clone();
-            let limiter = limiter.clone();
-            let rt = rt_ref.clone();
-
-            // Although async task is lightweight, it does consume resources, to prevent
-            // us from allocating too much unnecessary tasks concurrently, acquires a permit
-            // BEFORE constructing & spawning the future of pruning task,
-            let permit_prune_segment = semaphore.clone().acquire_owned().await.map_err(|e| {
-                ErrorCode::UnexpectedError(format!(
-                    "semaphore closed, acquire (filter future) permit failure, {}",
-                    e
-                ))
-            })?;
-
-            let segment_pruning_fut = {
-                let semaphore = semaphore.clone();
-                Self::prune_segment(
-                    ctx,
-                    segment_idx,
-                    segment_location,
-                    limiter,
-                    range_filter_pruner,
-                    filter_pruner,
-                    rt,
-                    semaphore,
-                    permit_prune_segment,
-                )
-                .instrument(tracing::debug_span!("filter_segment_with_storage_runtime"))
-            };
-
-            join_handlers.push(rt_ref.try_spawn(segment_pruning_fut)?);
+            common_base::base::tokio::task::spawn_blocking(move || {
+                candidates
+                    .par_chunks(RANGE_FILTER_CHUNK_SIZE)
+                    .fold(Vec::new, |mut kept, chunk| {
+                        for candidate in chunk {
+                            if range_filter_pruner.should_keep(
+                                &candidate.block_meta.col_stats,
+                                candidate.block_meta.row_count,
+                            ) {
+                                kept.push(Candidate {
+                                    segment_idx: candidate.segment_idx,
+                                    block_meta: candidate.block_meta.clone(),
+                                    slice_start: candidate.slice_start,
+                                    slice_len: candidate.slice_len,
+                                });
+                            }
+                        }
+                        kept
+                    })
+                    .reduce(Vec::new, |mut a, b| {
+                        a.extend(b);
+                        a
+                    })
+            })
+            .instrument(tracing::debug_span!("range_filter_worker_pool"))
+            .await
+            .map_err(|e| {
+                ErrorCode::UnexpectedError(format!("range filter worker panicked, {}", e))
+            })?
+        };
+
+        // 4. Deletion bitmap + bloom index: the remaining IO-bound step, genuinely split
+        // into a producer and a consumer rather than one future doing fetch-then-evaluate.
+        // `prefetch_candidate` (the producer, below) only fetches bytes — a candidate's
+        // deletion bitmap and bloom index, both through `meta_cache`, concurrently with each
+        // other — and evaluates nothing. A bounded window (`while window.len() <
+        // max_concurrent_prune`) keeps that many fetches in flight so object-store round
+        // trips overlap; a `Semaphore` sized to the same `max_concurrent_prune` would never
+        // actually block (at most `max_concurrent_prune` futures are ever in the window
+        // competing for `max_concurrent_prune` permits), so it was dropped rather than kept
+        // as dead weight. `evaluate_candidate` (the consumer, below) is synchronous CPU work
+        // over already-resident bytes: it runs the moment a fetch completes, so one
+        // candidate's evaluation overlaps the next candidate's fetch instead of waiting
+        // behind it.
+        let mut result = Vec::with_capacity(filtered.len());
+        let mut candidates = filtered.into_iter();
+        let mut window = FuturesUnordered::new();
+
+        'pipeline: loop {
+            while window.len() < max_concurrent_prune {
+                match candidates.next() {
+                    Some(candidate) => {
+                        if limiter.exceeded() {
+                            break 'pipeline;
+                        }
+                        let dal = dal.clone();
+                        window.push(
+                            Self::prefetch_candidate(candidate, dal, meta_cache)
+                                .instrument(tracing::debug_span!("prefetch_bitmap_and_index")),
+                        );
+                    }
+                    None => break,
+                }
+            }
+
+            if window.is_empty() {
+                break;
+            }
+
+            if let Some(item) = window.next().await {
+                let (candidate, deletion_bitmap, index_bytes) = item?;
+                if let Some(kept) = Self::evaluate_candidate(
+                    candidate,
+                    deletion_bitmap,
+                    index_bytes,
+                    &limiter,
+                    &filter_pruner,
+                ) {
+                    result.push(kept);
+                }
+            }
         }
 
-        let joint = future::try_join_all(join_handlers)
-            .instrument(tracing::debug_span!("join_all_filter_segment"))
-            .await
-            .map_err(|e| ErrorCode::StorageOther(format!("block pruning failure, {}", e)))?;
-
-        // 3. collect the result
-        let metas: Result<Vec<(usize, BlockMeta)>> = tracing::debug_span!("collect_result")
-            .in_scope(|| {
-                // flatten the collected block metas
-                let metas = joint
-                    .into_iter()
-                    .collect::<Result<Vec<_>>>()?
-                    .into_iter()
-                    .flatten();
-                Ok(metas.collect())
-            });
-        let metas = metas?;
+        // limit already exceeded: drain whatever is still in-flight in the window, without
+        // feeding it any new work.
+        while let Some(item) = window.next().await {
+            let (candidate, deletion_bitmap, index_bytes) = item?;
+            if let Some(kept) = Self::evaluate_candidate(
+                candidate,
+                deletion_bitmap,
+                index_bytes,
+                &limiter,
+                &filter_pruner,
+            ) {
+                result.push(kept);
+            }
+        }
+
+        tracing::Span::current().record("bloom_index_cache.hits", meta_cache.bloom_indexes.hits());
+        tracing::Span::current().record(
+            "bloom_index_cache.misses",
+            meta_cache.bloom_indexes.misses(),
+        );
+        tracing::Span::current().record(
+            "deletion_bitmap_cache.hits",
+            meta_cache.deletion_bitmaps.hits(),
+        );
+        tracing::Span::current().record(
+            "deletion_bitmap_cache.misses",
+            meta_cache.deletion_bitmaps.misses(),
+        );
 
         // if there are ordering + limit clause, use topn pruner
 
@@ -200,105 +420,181 @@ impl BlockPruner {
             let limit = push_down.limit.unwrap();
             let sort = push_down.order_by.clone();
             let tpruner = topn_pruner::TopNPrunner::new(schema, sort, limit);
-            return tpruner.prune(metas);
+            return tpruner.prune(result);
         }
 
-        Ok(metas)
+        Ok(result)
     }
 
-    async fn prune_segment(
-        ctx: Arc<dyn TableContext>,
-        segment_idx: SegmentIndex,
-        location: Location,
-        limiter: LimiterPruner,
-        range_filter_pruner: Arc<dyn RangeFilterPruner + Send + Sync>,
-        filter_pruner: Arc<dyn Pruner + Send + Sync>,
-        rt: Arc<Runtime>,
-        semaphore: Arc<Semaphore>,
-        permit: OwnedSemaphorePermit,
-    ) -> Result<Vec<(SegmentIndex, BlockMeta)>> {
-        let _ = permit;
-        let segment_reader = MetaReaders::segment_info_reader(ctx.as_ref());
+    // Producer: fetches this candidate's deletion bitmap and bloom index bytes, both
+    // through `meta_cache`, and evaluates nothing. The two fetches run concurrently with
+    // each other since neither depends on the other's result.
+    async fn prefetch_candidate(
+        candidate: Candidate,
+        dal: Operator,
+        meta_cache: &'static meta_cache::MetaCache,
+    ) -> Result<(Candidate, Option<Arc<Vec<u8>>>, Option<Arc<Vec<u8>>>)> {
+        let deletion_bitmap_loc = candidate.block_meta.deletion_bitmap_location.clone();
+        let deletion_bitmap_size = candidate.block_meta.deletion_bitmap_size;
+        let index_loc = candidate.block_meta.bloom_filter_index_location.clone();
+        let index_size = candidate.block_meta.bloom_filter_index_size;
 
-        // before read segment info, check if limit already exceeded
-        if limiter.exceeded() {
-            return Ok(vec![]);
-        }
+        let (deletion_bitmap, index_bytes) = future::try_join(
+            Self::fetch_cached(
+                &dal,
+                &meta_cache.deletion_bitmaps,
+                deletion_bitmap_loc,
+                deletion_bitmap_size,
+            ),
+            Self::fetch_cached(&dal, &meta_cache.bloom_indexes, index_loc, index_size),
+        )
+        .await?;
 
-        let (seg_loc, ver) = location;
-        let segment_info = segment_reader.read(seg_loc, None, ver).await?;
-        let mut result = Vec::with_capacity(segment_info.blocks.len());
-        if range_filter_pruner.should_keep(
-            &segment_info.summary.col_stats,
-            segment_info.summary.row_count,
-        ) {
-            let mut bloom_pruners = Vec::with_capacity(segment_info.blocks.len());
-            for (block_idx, block_meta) in segment_info.blocks.iter().enumerate() {
-                // prune block using range filter
-                if limiter.exceeded() {
-                    // before using filter to prune, check if limit already exceeded
-                    return Ok(result);
-                }
+        Ok((candidate, deletion_bitmap, index_bytes))
+    }
 
-                if range_filter_pruner.should_keep(&block_meta.col_stats, block_meta.row_count) {
-                    // prune block using bloom filter
-                    // different from min max
-                    let filter_pruner = filter_pruner.clone();
-                    let limiter = limiter.clone();
-                    let row_count = block_meta.row_count;
-                    let index_location = block_meta.bloom_filter_index_location.clone();
-                    let index_size = block_meta.bloom_filter_index_size;
-
-                    let permit_prune_block =
-                        semaphore.clone().acquire_owned().await.map_err(|e| {
-                            ErrorCode::UnexpectedError(format!(
-                                "semaphore closed, acquire (filter future) permit failure, {}",
-                                e
-                            ))
-                        })?;
-                    let h = rt.spawn(
-                        Self::prune_blocks(
-                            index_location,
-                            index_size,
-                            limiter,
-                            filter_pruner,
-                            block_idx,
-                            permit_prune_block,
-                            row_count,
-                        )
-                        .instrument(tracing::debug_span!("filter_using_bloom_index")),
-                    );
-                    bloom_pruners.push(h);
-                }
-            }
-            let joint = future::try_join_all(bloom_pruners)
-                .await
-                .map_err(|e| ErrorCode::StorageOther(format!("block pruning failure, {}", e)))?;
-            for item in joint {
-                let (block_idx, keep) = item?;
-                if keep {
-                    let block = &segment_info.blocks[block_idx];
-                    result.push((segment_idx, block.clone()))
+    // Fetches the bytes at `location` through `cache`, or `None` if there is no location at
+    // all (the block has no recorded bitmap/index of this kind).
+    async fn fetch_cached(
+        dal: &Operator,
+        cache: &meta_cache::LruCache<Vec<u8>>,
+        location: Option<Location>,
+        size: u64,
+    ) -> Result<Option<Arc<Vec<u8>>>> {
+        let location = match location {
+            Some(location) => location,
+            None => return Ok(None),
+        };
+        let bytes = cache
+            .get_or_load(&location, || {
+                let location = location.clone();
+                async move {
+                    let bytes = dal.object(&location.0).range_read(0..size).await?;
+                    Ok(Arc::new(bytes))
                 }
-            }
-        }
-        Ok::<_, ErrorCode>(result)
+            })
+            .await
+            .map_err(ErrorCode::from)?;
+        Ok(Some(bytes))
     }
-    async fn prune_blocks(
-        index_location: Option<Location>,
-        index_size: u64,
-        limiter: LimiterPruner,
-        filter_pruner: Arc<dyn Pruner + Send + Sync>,
-        block_idx: BlockIndex,
-        permit: OwnedSemaphorePermit,
-        row_count: u64,
-    ) -> Result<(BlockIndex, bool)> {
-        let _ = permit;
-        if limiter.within_limit(row_count)
-            && filter_pruner.should_keep(&index_location, index_size).await
+
+    // Consumer: CPU-only now that both fetches are resident. Deletion vectors are consulted
+    // first: a block whose slice-overlapping rows are entirely soft-deleted is dropped
+    // without ever evaluating its (far more expensive) bloom index.
+    fn evaluate_candidate(
+        candidate: Candidate,
+        deletion_bitmap: Option<Arc<Vec<u8>>>,
+        index_bytes: Option<Arc<Vec<u8>>>,
+        limiter: &LimiterPruner,
+        filter_pruner: &Arc<dyn Pruner>,
+    ) -> Option<(SegmentIndex, BlockMeta)> {
+        let live_slice_row_count = Self::live_row_count_in_range(
+            deletion_bitmap.as_deref(),
+            candidate.slice_start,
+            candidate.slice_len,
+        );
+        if live_slice_row_count == 0 {
+            return None;
+        }
+
+        // Charge the limiter with the *live* rows of this slice, not `slice_len`: rows the
+        // deletion bitmap marks as deleted were never going to be returned either, and
+        // charging for them makes `within_limit` trip before enough live rows have actually
+        // been found, short-changing the query's LIMIT.
+        if limiter.within_limit(live_slice_row_count)
+            && filter_pruner.should_keep(index_bytes.as_deref().map(|v| v.as_slice()))
         {
-            return Ok::<_, ErrorCode>((block_idx, true));
+            return Some((candidate.segment_idx, candidate.block_meta));
         }
-        Ok::<_, ErrorCode>((block_idx, false))
+        None
+    }
+
+    // Missing deletion bitmap means "all rows live". Otherwise `count_zeros`, restricted to
+    // `[start, start + len)`, gives the number of rows *within the slice* the bitmap marks
+    // as deleted — rows it marks deleted outside that sub-range don't affect how many of
+    // this block's slice rows are actually live.
+    fn live_row_count_in_range(deletion_bitmap: Option<&[u8]>, start: u64, len: u64) -> u64 {
+        let bitmap = match deletion_bitmap {
+            Some(bitmap) => bitmap,
+            None => return len,
+        };
+        let deleted = count_zeros(bitmap, start as usize, len as usize) as u64;
+        len.saturating_sub(deleted)
+    }
+
+    // `row_count` among `deleted_row_count` rows marked soft-deleted, from metadata alone —
+    // no bitmap bytes read. Used for the OFFSET/LIMIT Before/Overlapping/After walk, which
+    // must count over the rows a query can actually see, not the raw physical row count.
+    fn live_row_count(row_count: u64, deleted_row_count: u64) -> u64 {
+        row_count.saturating_sub(deleted_row_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn live_row_count_no_bitmap_means_all_live() {
+        assert_eq!(BlockPruner::live_row_count_in_range(None, 0, 8), 8);
+    }
+
+    #[test]
+    fn live_row_count_fully_deleted_slice_is_zero() {
+        // all 8 bits unset: every row in [0, 8) is soft-deleted.
+        let bitmap = [0b0000_0000u8];
+        assert_eq!(BlockPruner::live_row_count_in_range(Some(&bitmap), 0, 8), 0);
+    }
+
+    #[test]
+    fn live_row_count_partial_overlap_counts_only_live_rows() {
+        // bit i set = row i live. rows 2 and 3 are unset (deleted), the rest are live.
+        let bitmap = [0b1111_0011u8];
+        assert_eq!(BlockPruner::live_row_count_in_range(Some(&bitmap), 0, 8), 6);
+    }
+
+    #[test]
+    fn live_row_count_ignores_deletions_outside_the_requested_range() {
+        // rows 0..4 live, rows 4..8 deleted. Restricting to the sub-range [0, 4) must not be
+        // affected by the deletions sitting outside it.
+        let bitmap = [0b0000_1111u8];
+        assert_eq!(BlockPruner::live_row_count_in_range(Some(&bitmap), 0, 4), 4);
+        assert_eq!(BlockPruner::live_row_count_in_range(Some(&bitmap), 4, 4), 0);
+    }
+
+    #[test]
+    fn live_row_count_subtracts_deletions_from_metadata_alone() {
+        assert_eq!(BlockPruner::live_row_count(10, 0), 10);
+        assert_eq!(BlockPruner::live_row_count(10, 10), 0);
+        assert_eq!(BlockPruner::live_row_count(10, 4), 6);
+    }
+
+    #[test]
+    fn classify_slice_over_live_counts_does_not_skip_a_later_segment_past_deletions() {
+        // segment 1: a single fully soft-deleted 10-row block (live == 0).
+        // segment 2: a single fully live 10-row block.
+        // `OFFSET 5 LIMIT 3` must resolve into segment 2: those 3 rows are legitimately the
+        // first 3 live rows of the table. Classifying by *raw* row_count instead would put
+        // segment 2's cumulative offset at 10, past `slice_end` (8), wrongly call it `After`,
+        // and `break` before segment 2 is ever looked at — the bug this test guards against.
+        let seg1_live = BlockPruner::live_row_count(10, 10);
+        let seg2_live = BlockPruner::live_row_count(10, 0);
+
+        let offset = 5u64;
+        let slice_len = 3u64;
+
+        let seg1_offset = 0u64;
+        assert_eq!(
+            limiter::classify_slice(seg1_offset, seg1_live, offset, slice_len),
+            limiter::SlicePosition::Before
+        );
+
+        // the next segment's cumulative offset is carried forward using the *live* count of
+        // the previous one, not its raw row_count.
+        let seg2_offset = seg1_offset + seg1_live;
+        assert_eq!(
+            limiter::classify_slice(seg2_offset, seg2_live, offset, slice_len),
+            limiter::SlicePosition::Overlapping { start: 5, len: 3 }
+        );
     }
 }